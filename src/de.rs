@@ -0,0 +1,117 @@
+//! Lenient numeric deserialization, enabled by the `lenient_numeric` feature.
+//!
+//! [`FlocoVisitor`] accepts any numeric representation a deserializer hands it (an integer
+//! literal, a `u64`/`i64`, an `f32`/`f64`, ...), casts it into `F` via `num_traits`, and then
+//! runs it through `C::try_new` so the marker constraint is still enforced regardless of the
+//! wire type. It's driven through `deserialize_f64` rather than `deserialize_any`: the latter
+//! is rejected outright by non-self-describing no_std formats like `serde_json_core`, while
+//! `deserialize_f64` still lets a format that doesn't natively have an `f64` call back into
+//! whichever `visit_*` method matches the data it actually holds.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use floatd::FloatD;
+use num_traits::NumCast;
+use serde::de::{self, Visitor};
+
+use crate::{Constrained, Floco};
+
+/// Visitor that coerces any incoming numeric primitive into `F` before validating it against `C`.
+pub(crate) struct FlocoVisitor<F, C>(pub(crate) PhantomData<(F, C)>);
+
+impl<'de, F, C> Visitor<'de> for FlocoVisitor<F, C>
+where
+    F: FloatD,
+    C: Constrained<F>,
+{
+    type Value = Floco<F, C>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a numeric value")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        cast_and_validate::<F, C, E>(value)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        cast_and_validate::<F, C, E>(value)
+    }
+
+    fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        cast_and_validate::<F, C, E>(value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        cast_and_validate::<F, C, E>(value)
+    }
+}
+
+fn cast_and_validate<F, C, E>(value: impl NumCast + fmt::Display + Copy) -> Result<Floco<F, C>, E>
+where
+    F: FloatD,
+    C: Constrained<F>,
+    E: de::Error,
+{
+    let casted: F = NumCast::from(value).ok_or_else(|| out_of_range_error::<E>(value))?;
+    C::try_new(casted).map_err(E::custom)
+}
+
+#[cfg(feature = "std")]
+use std::{format, string::String};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{format, string::String};
+
+/// Builds the "doesn't fit" message. With `alloc` or `std` enabled this includes the offending
+/// value; without an allocator it falls back to a static message.
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn out_of_range_message(display_value: impl fmt::Display) -> String {
+    format!("value {display_value} does not fit in the target float type")
+}
+
+#[cfg(not(any(feature = "alloc", feature = "std")))]
+fn out_of_range_message(_display_value: impl fmt::Display) -> &'static str {
+    "value does not fit in the target float type"
+}
+
+/// Builds the "doesn't fit" deserialization error from [`out_of_range_message`].
+fn out_of_range_error<E: de::Error>(display_value: impl fmt::Display) -> E {
+    E::custom(out_of_range_message(display_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn out_of_range_message_includes_the_offending_value() {
+        assert_eq!(
+            out_of_range_message(42u64),
+            "value 42 does not fit in the target float type"
+        );
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "alloc", feature = "std")))]
+    fn out_of_range_message_falls_back_to_a_static_message() {
+        assert_eq!(
+            out_of_range_message(42u64),
+            "value does not fit in the target float type"
+        );
+    }
+}
@@ -52,12 +52,19 @@
 //! changes the errors from thiserror-core to thiserror. Floco should compile on stable if std is
 //! enabled, but will require the [error_in_core][`eiclink`] feature for no_std builds.
 //!
+//! There's also an intermediate `alloc` feature tier for no_std users who have a global
+//! allocator but don't want to pull in all of std. `Constrained::Error` is just bound by
+//! `Display`, so a downstream `Constrained` impl could always use an owned error type like
+//! `alloc::string::String`, with or without this feature. What `alloc` actually changes is
+//! this crate's own internal error formatting: with it (or `std`) enabled, things like the
+//! `lenient_numeric` visitor's out-of-range message can include the offending value instead of
+//! being a static string.
+//!
 //! Floco is compatible with any type that implements the [float][`ntFloatlink`] trait from
 //! the num_traits crate. TryFrom conversions are implemented from f32 and f64 for
 //! convenience.
 //!
 //! # Roadmap
-//! - At some point I intend to implement the ops traits on the Floco struct.
 //! - At some point I intend to add a macro to reduce the newtype boilerplate.
 //! - I want to create a similar struct that also contains generic [uom][`uomlink`] dimensions, but might just put that in a separate crate.
 //! - Not sure what to do with the Copy trait. Need to think that through.
@@ -96,6 +103,18 @@ compile_error!(
     "The 'libm' (enabled by default) and 'std_math' features cannot be enabled simultaneously."
 );
 
+// pull in the alloc crate for the `alloc` feature tier; `std` already re-exports it, so this is
+// only needed for no_std-with-allocator builds.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+
+pub mod presets;
+
+mod ops;
+
+#[cfg(feature = "lenient_numeric")]
+mod de;
+
 // use the std version if available
 #[cfg(feature = "std")]
 use std::fmt::{Debug, Display};
@@ -144,6 +163,14 @@ where
         self.0 = new_val;
     }
 
+    /// Updates a floco's inner value, checking for validity only in debug builds.
+    /// In debug builds an invalid value panics via `debug_assert!`, printing `C::emit_error`.
+    /// In release builds this is equivalent to [`Floco::mutate_unchecked`].
+    pub fn mutate_debug_checked(&mut self, new_val: F) {
+        debug_assert!(C::is_valid(new_val), "{}", C::emit_error(new_val));
+        self.0 = new_val;
+    }
+
     /// Fallible constructor. Equivalent to the try_new in the marker type's impl.
     #[allow(dead_code)]
     pub fn try_new(value: F) -> Result<Self, C::Error> {
@@ -174,12 +201,27 @@ where
 {
     /// Deserializing a number into a Floco instance activates the constraining type's validity check.
     /// Will return a Result<Err> if the validity criteria are not met.
+    ///
+    /// With the `lenient_numeric` feature enabled, this accepts any numeric representation the
+    /// deserializer hands it (e.g. an integer literal or a `u64`/`i64` from a self-describing
+    /// format) instead of requiring the wire type to exactly match `F`. This is requested via
+    /// `deserialize_f64` rather than `deserialize_any`, since `deserialize_any` is unsupported by
+    /// non-self-describing no_std formats such as `serde_json_core`; a conforming deserializer is
+    /// still free to call whichever `visit_*` method matches the data it actually holds.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let value = F::deserialize(deserializer)?;
-        C::try_new(value).map_err(serde::de::Error::custom)
+        #[cfg(not(feature = "lenient_numeric"))]
+        {
+            let value = F::deserialize(deserializer)?;
+            C::try_new(value).map_err(serde::de::Error::custom)
+        }
+
+        #[cfg(feature = "lenient_numeric")]
+        {
+            deserializer.deserialize_f64(crate::de::FlocoVisitor(PhantomData))
+        }
     }
 }
 
@@ -248,6 +290,16 @@ where
             Err(Self::emit_error(value))
         }
     }
+
+    /// Constructor that checks validity only in debug builds.
+    /// In debug builds an invalid value panics via `debug_assert!`, printing `emit_error`'s
+    /// `Display` output. In release builds the check compiles away entirely, making this as
+    /// cheap as constructing a Floco directly, just as [`Floco::mutate_unchecked`] is an
+    /// unchecked write.
+    fn new_debug_checked(value: F) -> Floco<F, Self> {
+        debug_assert!(Self::is_valid(value), "{}", Self::emit_error(value));
+        Floco::<F, Self>(value, PhantomData)
+    }
 }
 
 #[cfg(test)]
@@ -326,6 +378,15 @@ mod tests {
         assert!(should_be_error.is_err())
     }
 
+    #[test]
+    #[cfg(feature = "lenient_numeric")]
+    fn lenient_numeric_accepts_integer_literal_via_serde_json_core() {
+        let to_be_deserialized = "5";
+        let (should_be_ok, _): (Floco<f64, Foo>, usize) =
+            serde_json_core::from_str(to_be_deserialized).unwrap();
+        assert_eq!(should_be_ok.get(), 5.0f64)
+    }
+
     #[test]
     fn serialization_grabs_inner_float() {
         let to_be_serialized = Foo::try_new(42.0f64).unwrap();
@@ -377,4 +438,32 @@ mod tests {
         let _x = ipsum.mutate_unchecked(2.0f64);
         assert!(ipsum.0 == 2.0f64);
     }
+
+    #[test]
+    fn debug_checked_construction_works() {
+        let ipsum = Qux::new_debug_checked(1.0f64);
+        assert!(ipsum.0 == 1.0f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "omg this is a bad qux")]
+    #[cfg(debug_assertions)]
+    fn debug_checked_construction_catches_errors() {
+        let _ipsum = Qux::new_debug_checked(-1.0f64);
+    }
+
+    #[test]
+    fn debug_checked_mutability_works() {
+        let mut ipsum = Qux::try_new(1.0f64).unwrap();
+        ipsum.mutate_debug_checked(2.0f64);
+        assert!(ipsum.0 == 2.0f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "omg this is a bad qux")]
+    #[cfg(debug_assertions)]
+    fn debug_checked_mutability_catches_errors() {
+        let mut ipsum = Qux::try_new(1.0f64).unwrap();
+        ipsum.mutate_debug_checked(-2.0f64);
+    }
 }
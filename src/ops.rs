@@ -0,0 +1,238 @@
+//! Arithmetic operators for [`Floco`], plus an optional passthrough of the inspection half of
+//! `num_traits::Float`.
+//!
+//! `Add`/`Sub`/`Mul`/`Div`/`Neg` operate on the inner float and re-validate the result against
+//! `C`. Because these traits fix `Output` to `Self`, an invalid result can't be reported through
+//! a `Result` the way [`Floco::mutate`] does: instead it's guarded by `debug_assert!` (panicking
+//! in debug builds, compiled away in release), mirroring how this crate already handles
+//! uncontrolled writes via [`Floco::mutate_debug_checked`]. Callers who want to handle an invalid
+//! result instead of risking a panic should use the `checked_*` methods below.
+
+#[cfg(feature = "std")]
+use std::marker::PhantomData;
+
+#[cfg(not(feature = "std"))]
+use core::marker::PhantomData;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Constrained, Floco};
+use floatd::FloatD;
+
+impl<F, C> Floco<F, C>
+where
+    F: FloatD,
+    C: Constrained<F>,
+{
+    /// Adds two Flocos, re-validating the result instead of panicking on violation.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, C::Error> {
+        C::try_new(self.get() + rhs.get())
+    }
+
+    /// Subtracts two Flocos, re-validating the result instead of panicking on violation.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, C::Error> {
+        C::try_new(self.get() - rhs.get())
+    }
+
+    /// Multiplies two Flocos, re-validating the result instead of panicking on violation.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, C::Error> {
+        C::try_new(self.get() * rhs.get())
+    }
+
+    /// Divides two Flocos, re-validating the result instead of panicking on violation.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, C::Error> {
+        C::try_new(self.get() / rhs.get())
+    }
+
+    /// Negates a Floco, re-validating the result instead of panicking on violation.
+    pub fn checked_neg(self) -> Result<Self, C::Error> {
+        C::try_new(-self.get())
+    }
+}
+
+macro_rules! impl_validated_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<F, C> $trait for Floco<F, C>
+        where
+            F: FloatD,
+            C: Constrained<F>,
+        {
+            type Output = Floco<F, C>;
+
+            /// Performs the operation on the inner float, then re-validates the result.
+            /// Panics via `debug_assert!` (printing `C::emit_error`) if the result is invalid;
+            /// this check compiles away entirely in release builds. Use the `checked_*` methods
+            /// on [`Floco`] if you'd rather receive a `Result`.
+            fn $method(self, rhs: Self) -> Self::Output {
+                let result = self.get() $op rhs.get();
+                debug_assert!(C::is_valid(result), "{}", C::emit_error(result));
+                Floco::<F, C>(result, PhantomData)
+            }
+        }
+    };
+}
+
+impl_validated_op!(Add, add, +);
+impl_validated_op!(Sub, sub, -);
+impl_validated_op!(Mul, mul, *);
+impl_validated_op!(Div, div, /);
+
+impl<F, C> Neg for Floco<F, C>
+where
+    F: FloatD,
+    C: Constrained<F>,
+{
+    type Output = Floco<F, C>;
+
+    /// Negates the inner float, then re-validates the result. Panics via `debug_assert!`
+    /// (printing `C::emit_error`) if the result is invalid; this check compiles away
+    /// entirely in release builds. Use [`Floco::checked_neg`] if you'd rather receive a
+    /// `Result`.
+    fn neg(self) -> Self::Output {
+        let result = -self.get();
+        debug_assert!(C::is_valid(result), "{}", C::emit_error(result));
+        Floco::<F, C>(result, PhantomData)
+    }
+}
+
+/// Forwards the inspection half of `num_traits::Float` to the inner value, as inherent methods
+/// with the same names, so callers don't have to call `.get()` first for these common checks.
+/// This does *not* implement `num_traits::Float` itself (the signatures here deliberately take
+/// `&self` and return the raw `F` instead of `Self`, since `C` may reject the result of e.g.
+/// `abs()` or `signum()`), so a `Floco` still can't be passed to a function generic over
+/// `T: Float`. These only inspect or reshape the inner float (never re-wrap it), so there's no
+/// constraint to re-validate.
+#[cfg(feature = "float_passthrough")]
+impl<F, C> Floco<F, C>
+where
+    F: FloatD,
+    C: Constrained<F>,
+{
+    /// Forwards to the inner value's `is_nan`.
+    pub fn is_nan(&self) -> bool {
+        self.get().is_nan()
+    }
+
+    /// Forwards to the inner value's `is_infinite`.
+    pub fn is_infinite(&self) -> bool {
+        self.get().is_infinite()
+    }
+
+    /// Forwards to the inner value's `is_finite`.
+    pub fn is_finite(&self) -> bool {
+        self.get().is_finite()
+    }
+
+    /// Forwards to the inner value's `is_normal`.
+    pub fn is_normal(&self) -> bool {
+        self.get().is_normal()
+    }
+
+    /// Forwards to the inner value's `classify`.
+    pub fn classify(&self) -> core::num::FpCategory {
+        self.get().classify()
+    }
+
+    /// Forwards to the inner value's `is_sign_positive`.
+    pub fn is_sign_positive(&self) -> bool {
+        self.get().is_sign_positive()
+    }
+
+    /// Forwards to the inner value's `is_sign_negative`.
+    pub fn is_sign_negative(&self) -> bool {
+        self.get().is_sign_negative()
+    }
+
+    /// Forwards to the inner value's `abs`. Returns the raw `F`, not a re-validated `Floco`,
+    /// since `C` may not accept the result (e.g. a `Negative` constraint never would).
+    pub fn abs(&self) -> F {
+        self.get().abs()
+    }
+
+    /// Forwards to the inner value's `signum`. Returns the raw `F`, not a re-validated `Floco`,
+    /// for the same reason as [`Floco::abs`].
+    pub fn signum(&self) -> F {
+        self.get().signum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Constrained;
+
+    struct Pos;
+
+    impl Constrained<f64> for Pos {
+        type Error = &'static str;
+
+        fn is_valid(value: f64) -> bool {
+            value > 0.0
+        }
+
+        fn emit_error(_value: f64) -> Self::Error {
+            "value is not positive"
+        }
+    }
+
+    #[test]
+    fn add_keeps_valid_result() {
+        let a = Pos::try_new(1.0).unwrap();
+        let b = Pos::try_new(2.0).unwrap();
+        assert_eq!((a + b).get(), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "value is not positive")]
+    #[cfg(debug_assertions)]
+    fn sub_panics_on_invalid_result() {
+        let a = Pos::try_new(1.0).unwrap();
+        let b = Pos::try_new(2.0).unwrap();
+        let _ = a - b;
+    }
+
+    #[test]
+    fn mul_keeps_valid_result() {
+        let a = Pos::try_new(2.0).unwrap();
+        let b = Pos::try_new(3.0).unwrap();
+        assert_eq!((a * b).get(), 6.0);
+    }
+
+    #[test]
+    fn div_keeps_valid_result() {
+        let a = Pos::try_new(6.0).unwrap();
+        let b = Pos::try_new(2.0).unwrap();
+        assert_eq!((a / b).get(), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "value is not positive")]
+    #[cfg(debug_assertions)]
+    fn neg_panics_on_invalid_result() {
+        let a = Pos::try_new(1.0).unwrap();
+        let _ = -a;
+    }
+
+    #[test]
+    fn checked_sub_reports_invalid_result_instead_of_panicking() {
+        let a = Pos::try_new(1.0).unwrap();
+        let b = Pos::try_new(2.0).unwrap();
+        assert!(a.checked_sub(b).is_err());
+    }
+
+    #[test]
+    fn checked_div_works() {
+        let a = Pos::try_new(6.0).unwrap();
+        let b = Pos::try_new(2.0).unwrap();
+        assert_eq!(a.checked_div(b).unwrap().get(), 3.0);
+    }
+
+    #[test]
+    #[cfg(feature = "float_passthrough")]
+    fn passthrough_forwards_inspection_methods() {
+        let a = Pos::try_new(4.0).unwrap();
+        assert!(!a.is_nan());
+        assert!(a.is_finite());
+        assert_eq!(a.abs(), 4.0);
+        assert_eq!(a.signum(), 1.0);
+    }
+}
@@ -0,0 +1,195 @@
+//! Ready-made marker types for the most common restricted-float families.
+//!
+//! Each type here implements [`Constrained`] generically over any `F: FloatD`, so they can be
+//! used directly as the `C` parameter of a [`Floco`] without writing a marker type by hand.
+//! They cover the same ground as the restricted-float types other crates ship (see the
+//! "Alternative / Related Crates" section in the crate docs), while custom bounds are still
+//! just as easy to express by implementing [`Constrained`] directly.
+//!
+//! [`Constrained`]: crate::Constrained
+//! [`Floco`]: crate::Floco
+
+use crate::Constrained;
+use floatd::FloatD;
+
+/// Rejects NaN. Accepts everything else, including +/- infinity.
+pub struct NonNan;
+
+impl<F: FloatD> Constrained<F> for NonNan {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        !value.is_nan()
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is NaN"
+    }
+}
+
+/// Rejects NaN and +/- infinity.
+pub struct Finite;
+
+impl<F: FloatD> Constrained<F> for Finite {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite()
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is not finite"
+    }
+
+    fn get_default() -> F {
+        F::zero()
+    }
+}
+
+/// Accepts only strictly positive, finite values.
+pub struct Positive;
+
+impl<F: FloatD> Constrained<F> for Positive {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite() && value.is_sign_positive() && !value.is_zero()
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is not strictly positive"
+    }
+
+    fn get_default() -> F {
+        F::one()
+    }
+}
+
+/// Accepts only strictly negative, finite values.
+pub struct Negative;
+
+impl<F: FloatD> Constrained<F> for Negative {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite() && value.is_sign_negative() && !value.is_zero()
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is not strictly negative"
+    }
+
+    fn get_default() -> F {
+        -F::one()
+    }
+}
+
+/// Accepts finite values greater than or equal to zero.
+pub struct NonNegative;
+
+impl<F: FloatD> Constrained<F> for NonNegative {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite() && (value.is_sign_positive() || value.is_zero())
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is negative"
+    }
+}
+
+/// Accepts finite values less than or equal to zero.
+pub struct NonPositive;
+
+impl<F: FloatD> Constrained<F> for NonPositive {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite() && (value.is_sign_negative() || value.is_zero())
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is positive"
+    }
+}
+
+/// Accepts values in the closed range `0.0..=1.0`.
+pub struct UnitInterval;
+
+impl<F: FloatD> Constrained<F> for UnitInterval {
+    type Error = &'static str;
+
+    fn is_valid(value: F) -> bool {
+        value.is_finite() && value >= F::zero() && value <= F::one()
+    }
+
+    fn emit_error(_value: F) -> Self::Error {
+        "value is outside the unit interval [0.0, 1.0]"
+    }
+
+    fn get_default() -> F {
+        F::from(0.5).expect("0.5 is representable in any FloatD")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_nan_rejects_nan_but_accepts_infinity() {
+        assert!(!NonNan::is_valid(f64::NAN));
+        assert!(NonNan::is_valid(f64::INFINITY));
+        assert!(NonNan::is_valid(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn finite_rejects_positive_and_negative_infinity() {
+        assert!(!Finite::is_valid(f64::INFINITY));
+        assert!(!Finite::is_valid(f64::NEG_INFINITY));
+        assert!(!Finite::is_valid(f64::NAN));
+        assert!(Finite::is_valid(0.0));
+    }
+
+    #[test]
+    fn negative_zero_is_not_strictly_positive() {
+        assert!(!Positive::is_valid(-0.0f64));
+        assert!(!Positive::is_valid(0.0f64));
+    }
+
+    #[test]
+    fn negative_zero_is_not_strictly_negative() {
+        assert!(!Negative::is_valid(-0.0f64));
+        assert!(!Negative::is_valid(0.0f64));
+    }
+
+    #[test]
+    fn negative_zero_is_non_negative() {
+        assert!(NonNegative::is_valid(-0.0f64));
+    }
+
+    #[test]
+    fn negative_zero_is_non_positive() {
+        assert!(NonPositive::is_valid(-0.0f64));
+    }
+
+    #[test]
+    fn unit_interval_rejects_infinity() {
+        assert!(!UnitInterval::is_valid(f64::INFINITY));
+        assert!(!UnitInterval::is_valid(f64::NEG_INFINITY));
+        assert!(UnitInterval::is_valid(0.0));
+        assert!(UnitInterval::is_valid(1.0));
+    }
+
+    #[test]
+    fn defaults_are_sensible() {
+        assert_eq!(<NonNan as Constrained<f64>>::get_default(), 0.0);
+        assert_eq!(<Finite as Constrained<f64>>::get_default(), 0.0);
+        assert_eq!(<Positive as Constrained<f64>>::get_default(), 1.0);
+        assert_eq!(<Negative as Constrained<f64>>::get_default(), -1.0);
+        assert_eq!(<NonNegative as Constrained<f64>>::get_default(), 0.0);
+        assert_eq!(<NonPositive as Constrained<f64>>::get_default(), 0.0);
+        assert_eq!(<UnitInterval as Constrained<f64>>::get_default(), 0.5);
+    }
+}